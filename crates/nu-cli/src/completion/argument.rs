@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use crate::completion::matchers::Matcher;
+use crate::completion::Suggestion;
+
+/// How a command supplies completions for one of its argument slots.
+///
+/// This is the registration-side counterpart to the classic shell-completion protocol: instead
+/// of `NuCompleter` hardcoding a `command -> argument -> completer` table, a command describes,
+/// for a given slot, either a fixed list of values or a callback that behaves like a completion
+/// function driven by the full word list and the index of the word under the cursor (`COMP_CWORD`
+/// in bash terms).
+pub enum ArgumentCompletion {
+    /// A fixed list of values, matched the same way the default completers match.
+    Values(Vec<String>),
+
+    /// A callback invoked with the classified block for the current line (`None` if the line
+    /// didn't parse), the index of the word under the cursor, and the partial text of that word.
+    Dynamic(
+        Box<
+            dyn Fn(Option<&nu_parser::ClassifiedBlock>, usize, &str) -> Vec<Suggestion>
+                + Send
+                + Sync,
+        >,
+    ),
+}
+
+/// Which slot of a command invocation a registered completion applies to.
+///
+/// `Flag` and `Positional(None)` are kept distinct so a command that registers a catch-all
+/// completer for its unnamed positional arguments doesn't also hijack completion of its flag
+/// *names* (e.g. after typing `cmd --`).
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub enum ArgumentSlot {
+    /// The name of a flag, e.g. completing `--` in `cmd --`.
+    Flag,
+
+    /// A positional argument, named when the command gives its arguments names, or `None` for an
+    /// unnamed catch-all slot.
+    Positional(Option<String>),
+}
+
+/// Registry of per-command, per-argument-slot completions.
+///
+/// Commands (internal or external/plugin) register here so they can describe how their own
+/// positional and flag arguments complete, rather than `NuCompleter` needing to know about every
+/// command up front.
+#[derive(Default)]
+pub(crate) struct ArgumentCompletionRegistry {
+    entries: HashMap<(String, ArgumentSlot), ArgumentCompletion>,
+}
+
+impl ArgumentCompletionRegistry {
+    /// Register a completion for `command`'s `slot`.
+    pub fn register(
+        &mut self,
+        command: impl Into<String>,
+        slot: ArgumentSlot,
+        completion: ArgumentCompletion,
+    ) {
+        self.entries.insert((command.into(), slot), completion);
+    }
+
+    /// Complete `command`'s `slot`, if a completion has been registered for it.
+    ///
+    /// A named positional slot falls back to a catch-all `Positional(None)` registration, but
+    /// `Flag` never does -- a command that only registered a catch-all positional completer
+    /// shouldn't have it hijack completion of its flag *names*.
+    ///
+    /// `word_index` is the position, among the words the completion engine found on this line,
+    /// of the word currently being completed -- the `COMP_CWORD` analogue.
+    pub fn complete(
+        &self,
+        command: &str,
+        slot: &ArgumentSlot,
+        block: Option<&nu_parser::ClassifiedBlock>,
+        word_index: usize,
+        partial: &str,
+        matcher: &dyn Matcher,
+    ) -> Option<Vec<Suggestion>> {
+        let entry = self
+            .entries
+            .get(&(command.to_string(), slot.clone()))
+            .or_else(|| {
+                if let ArgumentSlot::Positional(Some(_)) = slot {
+                    self.entries
+                        .get(&(command.to_string(), ArgumentSlot::Positional(None)))
+                } else {
+                    None
+                }
+            })?;
+
+        let suggestions = match entry {
+            ArgumentCompletion::Values(values) => values
+                .iter()
+                .filter(|value| matcher.matches(partial, value))
+                .map(|value| Suggestion {
+                    replacement: value.clone(),
+                    display: value.clone(),
+                })
+                .collect(),
+            ArgumentCompletion::Dynamic(callback) => callback(block, word_index, partial),
+        };
+
+        Some(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SubstringMatcher;
+
+    impl Matcher for SubstringMatcher {
+        fn matches(&self, partial: &str, from: &str) -> bool {
+            from.contains(partial)
+        }
+    }
+
+    fn replacements(suggestions: Vec<Suggestion>) -> Vec<String> {
+        suggestions.into_iter().map(|s| s.replacement).collect()
+    }
+
+    #[test]
+    fn named_positional_falls_back_to_the_catch_all_registration() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Values(vec!["fallback".into()]),
+        );
+
+        let suggestions = registry
+            .complete(
+                "cmd",
+                &ArgumentSlot::Positional(Some("unregistered".into())),
+                None,
+                0,
+                "",
+                &SubstringMatcher,
+            )
+            .expect("the catch-all positional registration should be used");
+
+        assert_eq!(vec!["fallback".to_string()], replacements(suggestions));
+    }
+
+    #[test]
+    fn a_named_positional_registration_takes_priority_over_the_catch_all() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Values(vec!["catch-all".into()]),
+        );
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(Some("branch".into())),
+            ArgumentCompletion::Values(vec!["main".into()]),
+        );
+
+        let suggestions = registry
+            .complete(
+                "cmd",
+                &ArgumentSlot::Positional(Some("branch".into())),
+                None,
+                0,
+                "",
+                &SubstringMatcher,
+            )
+            .unwrap();
+
+        assert_eq!(vec!["main".to_string()], replacements(suggestions));
+    }
+
+    #[test]
+    fn flag_slot_does_not_fall_back_to_the_catch_all_positional() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Values(vec!["value".into()]),
+        );
+
+        assert!(registry
+            .complete("cmd", &ArgumentSlot::Flag, None, 0, "", &SubstringMatcher)
+            .is_none());
+    }
+
+    #[test]
+    fn an_empty_values_list_still_suppresses_the_default_completer() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Values(vec![]),
+        );
+
+        let suggestions = registry
+            .complete(
+                "cmd",
+                &ArgumentSlot::Positional(None),
+                None,
+                0,
+                "",
+                &SubstringMatcher,
+            )
+            .expect("a registered (even empty) completion should suppress the default");
+
+        assert!(suggestions.is_empty());
+    }
+
+    #[test]
+    fn values_are_filtered_by_the_matcher() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Values(vec!["main".into(), "dev".into()]),
+        );
+
+        let suggestions = registry
+            .complete(
+                "cmd",
+                &ArgumentSlot::Positional(None),
+                None,
+                0,
+                "ma",
+                &SubstringMatcher,
+            )
+            .unwrap();
+
+        assert_eq!(vec!["main".to_string()], replacements(suggestions));
+    }
+
+    #[test]
+    fn dynamic_completions_receive_the_word_index_and_partial() {
+        let mut registry = ArgumentCompletionRegistry::default();
+        registry.register(
+            "cmd",
+            ArgumentSlot::Positional(None),
+            ArgumentCompletion::Dynamic(Box::new(|_block, word_index, partial| {
+                vec![Suggestion {
+                    replacement: format!("{}:{}", word_index, partial),
+                    display: format!("{}:{}", word_index, partial),
+                }]
+            })),
+        );
+
+        let suggestions = registry
+            .complete(
+                "cmd",
+                &ArgumentSlot::Positional(None),
+                None,
+                2,
+                "partial",
+                &SubstringMatcher,
+            )
+            .unwrap();
+
+        assert_eq!(vec!["2:partial".to_string()], replacements(suggestions));
+    }
+}
@@ -0,0 +1,116 @@
+/// Fuzzy subsequence matcher.
+///
+/// Accepts a candidate when every character of the typed partial appears in it, in order, but
+/// not necessarily contiguously (e.g. `ecrt` matches `enter-container-runtime`). This is the
+/// matcher selected by the `"fuzzy"` `completion_match_method` config value.
+pub struct Matcher;
+
+impl super::Matcher for Matcher {
+    fn matches(&self, partial: &str, from: &str) -> bool {
+        score(partial, from).is_some()
+    }
+}
+
+const WORD_BOUNDARY_CHARS: &[char] = &['/', '-', '_'];
+
+/// Score how well `candidate` matches the subsequence `partial`.
+///
+/// Returns `None` when `partial` isn't a subsequence of `candidate`. Otherwise returns a score
+/// where higher is a better match, so callers can sort results best-first: contiguous runs and
+/// matches landing on a word boundary (right after `/`, `-`, `_`, or a lowercase-to-uppercase
+/// transition) are rewarded, while gaps between matched characters and unmatched characters
+/// before the first match are penalized.
+pub fn score(partial: &str, candidate: &str) -> Option<i64> {
+    if partial.is_empty() {
+        return Some(0);
+    }
+
+    let candidate: Vec<char> = candidate.chars().collect();
+    let query: Vec<char> = partial.chars().collect();
+
+    let mut total: i64 = 0;
+    let mut query_idx = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (idx, &c) in candidate.iter().enumerate() {
+        if query_idx >= query.len() {
+            break;
+        }
+
+        if !c.eq_ignore_ascii_case(&query[query_idx]) {
+            continue;
+        }
+
+        total += 1;
+
+        match last_match {
+            Some(last) if idx == last + 1 => total += 5,
+            Some(last) => total -= (idx - last - 1) as i64,
+            None => total -= idx as i64,
+        }
+
+        let at_boundary = idx == 0
+            || WORD_BOUNDARY_CHARS.contains(&candidate[idx - 1])
+            || (candidate[idx - 1].is_lowercase() && c.is_uppercase());
+
+        if at_boundary {
+            total += 10;
+        }
+
+        last_match = Some(idx);
+        query_idx += 1;
+    }
+
+    if query_idx == query.len() {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completion::matchers::Matcher as _;
+
+    #[test]
+    fn rejects_a_partial_that_is_not_a_subsequence() {
+        assert_eq!(None, score("xyz", "enter-container-runtime"));
+    }
+
+    #[test]
+    fn accepts_an_in_order_non_contiguous_subsequence() {
+        assert!(score("ecrt", "enter-container-runtime").is_some());
+    }
+
+    #[test]
+    fn empty_partial_matches_everything_with_zero_score() {
+        assert_eq!(Some(0), score("", "anything"));
+    }
+
+    #[test]
+    fn matches_mirrors_whether_score_returns_some() {
+        let matcher = Matcher;
+        assert!(matcher.matches("ecrt", "enter-container-runtime"));
+        assert!(!matcher.matches("xyz", "enter-container-runtime"));
+    }
+
+    #[test]
+    fn a_contiguous_match_outscores_a_scattered_one() {
+        let contiguous = score("ent", "enter").unwrap();
+        let scattered = score("etr", "enter").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn a_word_boundary_match_outscores_a_mid_word_match() {
+        let at_boundary = score("c", "enter-container").unwrap();
+        let mid_word = score("t", "enter-container").unwrap();
+        assert!(at_boundary > mid_word);
+    }
+
+    #[test]
+    fn matching_is_case_insensitive() {
+        assert!(score("ECRT", "enter-container-runtime").is_some());
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 use nu_source::Tag;
 
+use crate::completion::argument::{ArgumentCompletion, ArgumentCompletionRegistry, ArgumentSlot};
 use crate::completion::flag::FlagCompleter;
 use crate::completion::matchers;
 use crate::completion::matchers::Matcher;
@@ -14,6 +15,7 @@ pub(crate) struct NuCompleter {
     command: Box<dyn Completer>,
     flag: HashMap<String, Box<dyn Completer>>,
     argument: HashMap<Option<String>, HashMap<Option<String>, Box<dyn Completer>>>,
+    argument_registry: ArgumentCompletionRegistry,
     default_argument: Box<dyn Completer>,
 }
 
@@ -32,8 +34,11 @@ impl NuCompleter {
             Err(result) => result.partial,
         };
 
-        let locations = lite_block
-            .map(|block| nu_parser::classify_block(&block, &nu_context.registry))
+        let classified_block =
+            lite_block.map(|block| nu_parser::classify_block(&block, &nu_context.registry));
+
+        let locations = classified_block
+            .as_ref()
             .map(|block| completion::engine::completion_location(line, &block.block, pos))
             .unwrap_or_default();
 
@@ -48,8 +53,10 @@ impl NuCompleter {
             .unwrap_or_else(String::new);
 
         let matcher = matcher.as_str();
+        let fuzzy = matcher == "fuzzy";
         let matcher: &dyn Matcher = match matcher {
             "case-insensitive" => &matchers::case_insensitive::Matcher,
+            "fuzzy" => &matchers::fuzzy::Matcher,
             _ => &matchers::case_sensitive::Matcher,
         };
 
@@ -57,17 +64,34 @@ impl NuCompleter {
             (pos, Vec::new())
         } else {
             let pos = locations[0].span.start();
-            let suggestions = locations
+
+            // Each location has its own partial, so fuzzy ranking scores a suggestion against the
+            // partial of the location it actually came from, rather than assuming there's only
+            // one location on the line.
+            let mut suggestions: Vec<(String, Suggestion)> = locations
                 .into_iter()
-                .flat_map(|location| {
+                .enumerate()
+                .flat_map(|(word_index, location)| {
                     let partial = location.span.slice(line);
-                    match location.item {
+
+                    let suggestions = match location.item {
                         LocationType::Command => {
                             self.command.complete(context, partial, matcher.to_owned())
                         }
 
                         LocationType::Flag(cmd) => {
-                            if let Some(flag_completer) = self.flag.get(&cmd) {
+                            let registered = self.argument_registry.complete(
+                                &cmd,
+                                &ArgumentSlot::Flag,
+                                classified_block.as_ref(),
+                                word_index,
+                                partial,
+                                matcher,
+                            );
+
+                            if let Some(suggestions) = registered {
+                                suggestions
+                            } else if let Some(flag_completer) = self.flag.get(&cmd) {
                                 flag_completer.complete(context, partial, matcher.to_owned())
                             } else {
                                 let flag_completer = FlagCompleter { cmd };
@@ -76,37 +100,38 @@ impl NuCompleter {
                         }
 
                         LocationType::Argument(cmd, arg_name) => {
-                            const QUOTE_CHARS: &[char] = &['\'', '"', '`'];
-
-                            // TODO Find a better way to deal with quote chars. Can the completion
-                            //      engine relay this back to us? Maybe have two spans: inner and
-                            //      outer. The former is what we want to complete, the latter what
-                            //      we'd need to replace.
-                            let (quote_char, partial) = if partial.starts_with(QUOTE_CHARS) {
-                                let (head, tail) = partial.split_at(1);
-                                (Some(head), tail)
-                            } else {
-                                (None, partial)
-                            };
+                            // `partial` is the outer span: the whole token, quotes and all. The
+                            // completer itself should only ever see the inner span, i.e. the
+                            // unquoted text, so strip a surrounding quote pair here. `pos` above
+                            // is already the outer span's start, so the editor replaces the whole
+                            // quoted token regardless of what `requote` decides to do with it.
+                            let partial = strip_quotes(partial);
+
+                            let slot = ArgumentSlot::Positional(arg_name.clone());
+                            let registered = cmd.as_ref().and_then(|cmd| {
+                                self.argument_registry.complete(
+                                    cmd,
+                                    &slot,
+                                    classified_block.as_ref(),
+                                    word_index,
+                                    partial,
+                                    matcher,
+                                )
+                            });
 
-                            let partial = if let Some(quote_char) = quote_char {
-                                if partial.ends_with(quote_char) {
-                                    &partial[..partial.len() - 1]
-                                } else {
-                                    partial
-                                }
+                            let suggestions = if let Some(suggestions) = registered {
+                                suggestions
                             } else {
-                                partial
-                            };
+                                let arg_completer = self
+                                    .argument
+                                    .get(&cmd)
+                                    .and_then(|map| map.get(&arg_name).or_else(|| map.get(&None)))
+                                    .unwrap_or(&self.default_argument);
 
-                            let arg_completer = self
-                                .argument
-                                .get(&cmd)
-                                .and_then(|map| map.get(&arg_name).or_else(|| map.get(&None)))
-                                .unwrap_or(&self.default_argument);
+                                arg_completer.complete(context, partial, matcher)
+                            };
 
-                            arg_completer
-                                .complete(context, partial, matcher)
+                            suggestions
                                 .into_iter()
                                 .map(|s| Suggestion {
                                     replacement: requote(s.replacement),
@@ -116,13 +141,40 @@ impl NuCompleter {
                         }
 
                         LocationType::Variable => Vec::new(),
-                    }
+                    };
+
+                    let fuzzy_partial = strip_quotes(partial).to_string();
+                    suggestions
+                        .into_iter()
+                        .map(move |s| (fuzzy_partial.clone(), s))
+                        .collect::<Vec<_>>()
                 })
                 .collect();
 
+            if fuzzy {
+                suggestions.sort_by(|(a_partial, a), (b_partial, b)| {
+                    let a_score = matchers::fuzzy::score(a_partial, &a.display);
+                    let b_score = matchers::fuzzy::score(b_partial, &b.display);
+                    b_score.cmp(&a_score)
+                });
+            }
+
+            let suggestions = suggestions.into_iter().map(|(_, s)| s).collect();
+
             (pos, suggestions)
         }
     }
+
+    /// Register how `command`'s `slot` completes, letting a command supply its own completions
+    /// (a fixed list, or a callback) instead of relying on the generic per-type completers.
+    pub(crate) fn register_argument_completion(
+        &mut self,
+        command: impl Into<String>,
+        slot: ArgumentSlot,
+        completion: ArgumentCompletion,
+    ) {
+        self.argument_registry.register(command, slot, completion);
+    }
 }
 
 impl Default for NuCompleter {
@@ -139,39 +191,97 @@ impl Default for NuCompleter {
         let mut argument = HashMap::new();
         argument.insert(Some("cd".into()), temp);
 
-        NuCompleter {
+        let mut completer = NuCompleter {
             command: Box::new(CommandCompleter),
             flag: HashMap::new(),
             argument,
+            argument_registry: ArgumentCompletionRegistry::default(),
             default_argument: Box::new(PathCompleter),
-        }
+        };
+
+        completer.register_argument_completion(
+            "git",
+            ArgumentSlot::Positional(Some("branch".into())),
+            ArgumentCompletion::Dynamic(Box::new(|_block, _word_index, partial| {
+                git_branches()
+                    .into_iter()
+                    .filter(|branch| branch.contains(partial))
+                    .map(|branch| Suggestion {
+                        replacement: branch.clone(),
+                        display: branch,
+                    })
+                    .collect()
+            })),
+        );
+
+        completer
+    }
+}
+
+/// List local branch names via `git`, for completing e.g. `git checkout <branch>`.
+///
+/// Returns an empty list if `git` isn't on `PATH`, the current directory isn't a repository, or
+/// the output isn't valid UTF-8 -- completion should never fail the line just because branch
+/// listing didn't work.
+fn git_branches() -> Vec<String> {
+    std::process::Command::new("git")
+        .args(&["for-each-ref", "--format=%(refname:short)", "refs/heads/"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|stdout| stdout.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+const QUOTE_CHARS: &[char] = &['\'', '"', '`'];
+
+/// Split the outer span of an argument completion location into the inner text the completer
+/// should see, stripping a leading quote character and its matching trailing quote, if present.
+///
+/// Ideally `completion::engine` would report the inner and outer spans of a location separately,
+/// so quote handling lived in one place instead of being split between this function and
+/// `requote` below. That engine-level change is out of scope here -- `completion::engine` is
+/// shared by every location type, not just arguments -- so this function and `requote` remain the
+/// local, completer-side heuristic for quote-aware replacement.
+fn strip_quotes(partial: &str) -> &str {
+    if !partial.starts_with(QUOTE_CHARS) {
+        return partial;
+    }
+
+    let (quote, rest) = partial.split_at(1);
+    if rest.ends_with(quote) {
+        &rest[..rest.len() - 1]
+    } else {
+        rest
     }
 }
 
 fn requote(orig_value: String) -> String {
     let value: Cow<str> = rustyline::completion::unescape(&orig_value, Some('\\'));
 
-    let mut quotes = vec!['"', '\'', '`'];
-    let mut should_quote = false;
-    for c in value.chars() {
-        if c.is_whitespace() {
-            should_quote = true;
-        } else if let Some(index) = quotes.iter().position(|q| *q == c) {
-            should_quote = true;
-            quotes.swap_remove(index);
-        }
+    let needs_quoting = value
+        .chars()
+        .any(|c| c.is_whitespace() || QUOTE_CHARS.contains(&c));
+
+    if !needs_quoting {
+        return value.to_string();
     }
 
-    if should_quote {
-        if quotes.is_empty() {
-            // TODO we don't really have an escape character, so there isn't a great option right
-            //      now. One possibility is `{{$(char backtick)}}`
-            value.to_string()
-        } else {
-            let quote = quotes[0];
-            format!("{}{}{}", quote, value, quote)
+    if let Some(&quote) = QUOTE_CHARS.iter().find(|&&q| !value.contains(q)) {
+        return format!("{}{}{}", quote, value, quote);
+    }
+
+    // The value contains all three quote characters, so no unescaped quote pair can wrap it.
+    // Fall back to double quotes with backslash escaping.
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            escaped.push('\\');
         }
-    } else {
-        value.to_string()
+        escaped.push(c);
     }
+    escaped.push('"');
+    escaped
 }
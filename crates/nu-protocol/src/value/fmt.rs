@@ -0,0 +1,171 @@
+use crate::value::path::Path;
+
+/// An fd-style output template, e.g. `{}/{/}.bak` or `mv {} {.}.orig`.
+///
+/// Placeholders are resolved against the structured [`Path`] type, so splitting is correct
+/// across `/` and `\` instead of relying on ad-hoc string scanning:
+///
+/// - `{}`   the full path
+/// - `{/}`  the basename (last `Normal` component)
+/// - `{//}` the parent directory
+/// - `{.}`  the full path with its final extension removed
+/// - `{/.}` the basename without its extension
+///
+/// A template is parsed once with [`FormatTemplate::parse`] and can then be rendered per result
+/// row with [`FormatTemplate::render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatTemplate {
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(String),
+    FullPath,
+    BaseName,
+    ParentDir,
+    PathNoExt,
+    BaseNameNoExt,
+}
+
+/// An error parsing a [`FormatTemplate`], e.g. an unterminated or unrecognized `{...}`
+/// placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatTemplateParseError {
+    message: String,
+}
+
+impl std::fmt::Display for FormatTemplateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FormatTemplateParseError {}
+
+impl FormatTemplate {
+    /// Parse a template once into a sequence of literal/placeholder tokens.
+    pub fn parse(template: &str) -> Result<FormatTemplate, FormatTemplateParseError> {
+        let mut tokens = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '{' {
+                literal.push(c);
+                continue;
+            }
+
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+
+            let mut placeholder = String::new();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(c) => placeholder.push(c),
+                    None => {
+                        return Err(FormatTemplateParseError {
+                            message: format!("unterminated `{{` in format template `{}`", template),
+                        })
+                    }
+                }
+            }
+
+            let token = match placeholder.as_str() {
+                "" => Token::FullPath,
+                "/" => Token::BaseName,
+                "//" => Token::ParentDir,
+                "." => Token::PathNoExt,
+                "/." => Token::BaseNameNoExt,
+                other => {
+                    return Err(FormatTemplateParseError {
+                        message: format!(
+                            "unknown placeholder `{{{}}}` in format template `{}`",
+                            other, template
+                        ),
+                    })
+                }
+            };
+
+            tokens.push(token);
+        }
+
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(literal));
+        }
+
+        Ok(FormatTemplate { tokens })
+    }
+
+    /// Render this template for `path`.
+    pub fn render(&self, path: &Path) -> String {
+        let mut out = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Literal(s) => out.push_str(s),
+                Token::FullPath => out.push_str(&path.to_string()),
+                Token::BaseName => out.push_str(path.file_name().unwrap_or_default()),
+                Token::ParentDir => match path.parent() {
+                    Some(parent) => out.push_str(&parent.to_string()),
+                    // A bare filename has no parent directory; fd's `{//}` renders `.` (the
+                    // current directory) in that case rather than an empty string.
+                    None => out.push('.'),
+                },
+                Token::PathNoExt => out.push_str(&path.with_extension("").to_string()),
+                Token::BaseNameNoExt => out.push_str(path.file_stem().unwrap_or_default()),
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_full_path_and_basename_placeholders() {
+        let template = FormatTemplate::parse("{} -> {/}").unwrap();
+        let path = Path::from("a/b/c.txt");
+
+        assert_eq!("a/b/c.txt -> c.txt", template.render(&path));
+    }
+
+    #[test]
+    fn renders_the_parent_and_extension_placeholders() {
+        let template = FormatTemplate::parse("{//}/{/.}{.}").unwrap();
+        let path = Path::from("a/b/c.txt");
+
+        assert_eq!("a/b/ca/b/c", template.render(&path));
+    }
+
+    #[test]
+    fn parent_dir_of_a_bare_filename_is_the_current_directory() {
+        let template = FormatTemplate::parse("{//}").unwrap();
+        let path = Path::from("c.txt");
+
+        assert_eq!(".", template.render(&path));
+    }
+
+    #[test]
+    fn parent_dir_of_an_absolute_root_level_path_is_the_separator() {
+        let template = FormatTemplate::parse("{//}").unwrap();
+        let path = Path::from("/a.txt");
+
+        assert_eq!("/", template.render(&path));
+    }
+
+    #[test]
+    fn rejects_an_unknown_placeholder() {
+        assert!(FormatTemplate::parse("{bogus}").is_err());
+    }
+
+    #[test]
+    fn rejects_an_unterminated_placeholder() {
+        assert!(FormatTemplate::parse("{").is_err());
+    }
+}
@@ -105,8 +105,219 @@ impl Component {
     }
 }
 
+impl Path {
+    /// The path without its final component, if there is one.
+    ///
+    /// Returns `None` if the path has no components, if the last component isn't a `Normal` or
+    /// `HomeDir` one (e.g. `/` or `..`), or if dropping it would leave nothing (e.g. `~` or
+    /// `c.txt`).
+    pub fn parent(&self) -> Option<Path> {
+        match self.components.last()? {
+            Component::Normal(_) | Component::HomeDir(_) => {
+                let components = self.components[..self.components.len() - 1].to_vec();
+                if components.is_empty() {
+                    None
+                } else {
+                    Some(Path {
+                        components,
+                        separator: self.separator,
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The final component of the path, as a string, if it's a `Normal` component.
+    pub fn file_name(&self) -> Option<&str> {
+        match self.components.last()? {
+            Component::Normal(name) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The final component of the path, without its extension, if any.
+    pub fn file_stem(&self) -> Option<&str> {
+        let name = self.file_name()?;
+        match name.rfind(PARENT_CHAR) {
+            Some(0) | None => Some(name),
+            Some(idx) => Some(&name[..idx]),
+        }
+    }
+
+    /// The extension of the final component, if any, without the leading `.`.
+    pub fn extension(&self) -> Option<&str> {
+        let name = self.file_name()?;
+        match name.rfind(PARENT_CHAR) {
+            Some(0) | None => None,
+            Some(idx) => Some(&name[idx + PARENT_CHAR.len_utf8()..]),
+        }
+    }
+
+    /// Returns true if the first component is a `Root` or `Prefix`.
+    pub fn is_absolute(&self) -> bool {
+        matches!(
+            self.components.first(),
+            Some(Component::Root) | Some(Component::Prefix(_))
+        )
+    }
+
+    /// Append `other`'s components to this path, in place.
+    pub fn push(&mut self, other: impl Into<Path>) {
+        self.components.extend(other.into().components);
+    }
+
+    /// Return a new path with `other`'s components appended.
+    pub fn join(&self, other: impl Into<Path>) -> Path {
+        let mut joined = self.clone();
+        joined.push(other);
+        joined
+    }
+
+    /// Return a new path with the final component replaced by `file_name`.
+    ///
+    /// If the final component isn't a `Normal` or `HomeDir` one (or the path is empty),
+    /// `file_name` is appended instead.
+    pub fn with_file_name(&self, file_name: impl Into<String>) -> Path {
+        let mut components = match self.components.last() {
+            Some(Component::Normal(_)) | Some(Component::HomeDir(_)) => {
+                self.components[..self.components.len() - 1].to_vec()
+            }
+            _ => self.components.clone(),
+        };
+
+        components.push(Component::Normal(file_name.into()));
+
+        Path {
+            components,
+            separator: self.separator,
+        }
+    }
+
+    /// Return a new path with the final component's extension replaced by `extension`.
+    ///
+    /// Has no effect if the path has no final `Normal` component.
+    pub fn with_extension(&self, extension: &str) -> Path {
+        let stem = match self.file_stem() {
+            Some(stem) => stem.to_string(),
+            None => return self.clone(),
+        };
+
+        let file_name = if extension.is_empty() {
+            stem
+        } else {
+            format!("{}{}{}", stem, PARENT_CHAR, extension)
+        };
+
+        self.with_file_name(file_name)
+    }
+
+    /// True if `self` begins with all of `other`'s components.
+    ///
+    /// `Component::Normal` is compared case-sensitively on every platform, so path logic behaves
+    /// the same regardless of the host filesystem. The one exception is a drive letter
+    /// (`Prefix::Disk`/`VerbatimDisk`), which always compares case-insensitively since `C:` and
+    /// `c:` name the same drive. Use [`Path::starts_with_case_insensitive`] for filesystem-accurate
+    /// comparisons on case-insensitive filesystems (Windows, and macOS by default).
+    pub fn starts_with(&self, other: &Path) -> bool {
+        self.starts_with_impl(other, false)
+    }
+
+    /// Like [`Path::starts_with`], but compares every component case-insensitively.
+    pub fn starts_with_case_insensitive(&self, other: &Path) -> bool {
+        self.starts_with_impl(other, true)
+    }
+
+    /// True if `self` ends with all of `other`'s components. See [`Path::starts_with`] for the
+    /// case-sensitivity rules.
+    pub fn ends_with(&self, other: &Path) -> bool {
+        self.ends_with_impl(other, false)
+    }
+
+    /// Like [`Path::ends_with`], but compares every component case-insensitively.
+    pub fn ends_with_case_insensitive(&self, other: &Path) -> bool {
+        self.ends_with_impl(other, true)
+    }
+
+    /// True if `self` and `other` have the same components, ignoring case. The derived `Eq` is
+    /// the case-sensitive comparison; unlike [`Path::starts_with`], it also compares drive letters
+    /// and the separator exactly.
+    pub fn eq_ignore_case(&self, other: &Path) -> bool {
+        components_eq(&self.components, &other.components, true)
+    }
+
+    fn starts_with_impl(&self, other: &Path, case_insensitive: bool) -> bool {
+        if other.components.len() > self.components.len() {
+            return false;
+        }
+
+        let prefix = &self.components[..other.components.len()];
+        components_eq(prefix, &other.components, case_insensitive)
+    }
+
+    fn ends_with_impl(&self, other: &Path, case_insensitive: bool) -> bool {
+        if other.components.len() > self.components.len() {
+            return false;
+        }
+
+        let offset = self.components.len() - other.components.len();
+        let suffix = &self.components[offset..];
+        components_eq(suffix, &other.components, case_insensitive)
+    }
+}
+
+fn components_eq(a: &[Component], b: &[Component], case_insensitive: bool) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| component_eq(a, b, case_insensitive))
+}
+
+fn component_eq(a: &Component, b: &Component, case_insensitive: bool) -> bool {
+    match (a, b) {
+        (Component::Normal(x), Component::Normal(y)) => {
+            if case_insensitive {
+                x.eq_ignore_ascii_case(y)
+            } else {
+                x == y
+            }
+        }
+        (Component::HomeDir(x), Component::HomeDir(y)) if case_insensitive => match (x, y) {
+            (Some(x), Some(y)) => x.eq_ignore_ascii_case(y),
+            (None, None) => true,
+            _ => false,
+        },
+        // Drive letters always compare case-insensitively: `C:` and `c:` name the same drive.
+        (Component::Prefix(Prefix::Disk(x)), Component::Prefix(Prefix::Disk(y))) => {
+            x.eq_ignore_ascii_case(y)
+        }
+        (Component::Prefix(Prefix::VerbatimDisk(x)), Component::Prefix(Prefix::VerbatimDisk(y))) => {
+            x.eq_ignore_ascii_case(y)
+        }
+        (Component::Prefix(x), Component::Prefix(y)) if case_insensitive => match (x, y) {
+            (Prefix::Verbatim(x), Prefix::Verbatim(y))
+            | (Prefix::DeviceNS(x), Prefix::DeviceNS(y)) => x.eq_ignore_ascii_case(y),
+            (Prefix::VerbatimUNC(x1, x2), Prefix::VerbatimUNC(y1, y2))
+            | (Prefix::UNC(x1, x2), Prefix::UNC(y1, y2)) => {
+                x1.eq_ignore_ascii_case(y1) && x2.eq_ignore_ascii_case(y2)
+            }
+            _ => false,
+        },
+        _ => a == b,
+    }
+}
+
 impl std::fmt::Display for Path {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // `Component::Root` formats as an empty string -- it's only meant to contribute the
+        // separator *between* it and the component that follows. A path made up of nothing but
+        // `Root` (e.g. bare `/`, or the parent of an absolute single-component path) has no such
+        // follow-up component, so special-case it to the separator itself rather than silently
+        // rendering empty.
+        if self.components.len() == 1 && matches!(self.components[0], Component::Root) {
+            return write!(f, "{}", self.separator);
+        }
+
         if !self.components.is_empty() {
             self.components[0].format_with_separator(self.separator, f)?;
             for component in self.components[1..].iter() {
@@ -247,4 +458,90 @@ mod tests {
             path
         );
     }
+
+    #[test]
+    fn parent_drops_last_normal_component() {
+        let path = Path::from("a/b/c.txt");
+        assert_eq!(Some(Path::from("a/b")), path.parent());
+    }
+
+    #[test]
+    fn parent_of_bare_home_dir_is_none() {
+        let path = Path::from("~");
+        assert_eq!(None, path.parent());
+    }
+
+    #[test]
+    fn parent_of_home_dir_child_is_home_dir() {
+        let path = Path::from("~/foo");
+        assert_eq!(Some(Path::from("~")), path.parent());
+    }
+
+    #[test]
+    fn parent_of_an_absolute_root_level_path_is_the_root() {
+        let path = Path::from("/a.txt");
+        assert_eq!(Some(Path::from("/")), path.parent());
+        assert_eq!("/", path.parent().unwrap().to_string());
+    }
+
+    #[test]
+    fn file_name_returns_last_normal_component() {
+        let path = Path::from("a/b/c.txt");
+        assert_eq!(Some("c.txt"), path.file_name());
+    }
+
+    #[test]
+    fn file_stem_and_extension_split_on_final_dot() {
+        let path = Path::from("a/b/c.tar.gz");
+        assert_eq!(Some("c.tar"), path.file_stem());
+        assert_eq!(Some("gz"), path.extension());
+    }
+
+    #[test]
+    fn dotfiles_have_no_extension() {
+        let path = Path::from(".bashrc");
+        assert_eq!(Some(".bashrc"), path.file_stem());
+        assert_eq!(None, path.extension());
+    }
+
+    #[test]
+    fn with_extension_replaces_the_final_extension() {
+        let path = Path::from("a/b/c.txt");
+        assert_eq!(Path::from("a/b/c.md"), path.with_extension("md"));
+    }
+
+    #[test]
+    fn with_file_name_replaces_the_final_component() {
+        let path = Path::from("a/b/c.txt");
+        assert_eq!(Path::from("a/b/d.txt"), path.with_file_name("d.txt"));
+    }
+
+    #[test]
+    fn join_appends_components() {
+        let path = Path::from("a/b");
+        assert_eq!(Path::from("a/b/c.txt"), path.join(Path::from("c.txt")));
+    }
+
+    #[test]
+    fn is_absolute_checks_for_a_leading_root() {
+        assert!(Path::from("/a/b").is_absolute());
+        assert!(!Path::from("a/b").is_absolute());
+    }
+
+    #[test]
+    fn starts_with_and_ends_with_are_case_sensitive_by_default() {
+        let path = Path::from("a/B/c.txt");
+        assert!(path.starts_with(&Path::from("a/B")));
+        assert!(!path.starts_with(&Path::from("a/b")));
+        assert!(path.ends_with(&Path::from("B/c.txt")));
+        assert!(!path.ends_with(&Path::from("b/c.txt")));
+    }
+
+    #[test]
+    fn case_insensitive_variants_ignore_case() {
+        let path = Path::from("a/B/c.txt");
+        assert!(path.starts_with_case_insensitive(&Path::from("a/b")));
+        assert!(path.ends_with_case_insensitive(&Path::from("B/C.TXT")));
+        assert!(path.eq_ignore_case(&Path::from("A/b/C.TXT")));
+    }
 }